@@ -0,0 +1,90 @@
+//! Accessibility hook: announce the focused element on [[NavEvent::FocusChanged]].
+//!
+//! This makes the navigation system usable by screen-reader / blind players.
+//! Behind the `tts` feature, the focused [[FocusLabel]] is spoken through a
+//! [[bevy_tts::Tts]] resource; when the feature is off, a lightweight
+//! [[FocusAnnounce]] event is emitted instead so users can wire up their own
+//! speech/sound.
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+use non_empty_vec::NonEmpty;
+
+use crate::NavEvent;
+
+/// A human-readable label for a [[crate::Focusable]], announced whenever
+/// that element gains focus.
+#[derive(Component, Clone, Debug)]
+pub struct FocusLabel(pub String);
+
+/// Emitted when the focused element changes and the `tts` feature is
+/// disabled, so users can wire up their own speech/sound.
+#[derive(Clone, Debug)]
+#[cfg(not(feature = "tts"))]
+pub struct FocusAnnounce {
+    pub entity: Entity,
+    pub label: String,
+    /// Set when the focus change entered or left a submenu, ie: the `to` path
+    /// is longer or shorter than the `from` path. `None` when both paths have
+    /// the same length, even if the focus moved to another [[crate::NavFence]].
+    pub context: Option<&'static str>,
+}
+
+/// "entered submenu"/"left submenu" when the focus change crossed a
+/// [[crate::NavFence]] boundary, detected from the `from`/`to` path lengths.
+fn context_for(from: &NonEmpty<Entity>, to: &NonEmpty<Entity>) -> Option<&'static str> {
+    match to.len().get().cmp(&from.len().get()) {
+        Ordering::Greater => Some("entered submenu"),
+        Ordering::Less => Some("left submenu"),
+        Ordering::Equal => None,
+    }
+}
+
+#[cfg(feature = "tts")]
+fn announce_focus_changes(
+    mut events: EventReader<NavEvent>,
+    labels: Query<&FocusLabel>,
+    mut tts: ResMut<bevy_tts::Tts>,
+) {
+    for event in events.iter() {
+        if let NavEvent::FocusChanged { to, from } = event {
+            if let Ok(label) = labels.get(*to.first()) {
+                let spoken = match context_for(from, to) {
+                    Some(context) => format!("{context}, {}", label.0),
+                    None => label.0.clone(),
+                };
+                let _ = tts.speak(spoken, true);
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "tts"))]
+fn announce_focus_changes(
+    mut events: EventReader<NavEvent>,
+    labels: Query<&FocusLabel>,
+    mut announcements: EventWriter<FocusAnnounce>,
+) {
+    for event in events.iter() {
+        if let NavEvent::FocusChanged { to, from } = event {
+            if let Ok(label) = labels.get(*to.first()) {
+                announcements.send(FocusAnnounce {
+                    entity: *to.first(),
+                    label: label.0.clone(),
+                    context: context_for(from, to),
+                });
+            }
+        }
+    }
+}
+
+/// Speaks (or announces) the [[FocusLabel]] of the newly focused element
+/// whenever a [[NavEvent::FocusChanged]] fires.
+pub struct AccessibilityPlugin;
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(not(feature = "tts"))]
+        app.add_event::<FocusAnnounce>();
+        app.add_system(announce_focus_changes);
+    }
+}
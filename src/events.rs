@@ -0,0 +1,84 @@
+use bevy::math::Vec2;
+use bevy::prelude::Entity;
+use non_empty_vec::NonEmpty;
+
+/// Requests to send to the navigation system to move the focus.
+///
+/// Send this through an `EventWriter<NavRequest>` to trigger a navigation
+/// update; [[crate::NavigationPlugin]] reacts to it and emits a [[NavEvent]]
+/// in response.
+#[derive(Clone, Copy, Debug)]
+pub enum NavRequest {
+    /// Move in 2d space, will move toward the requested direction among the
+    /// currently reachable [[crate::Focusable]]s
+    Move(Direction),
+    /// Move within a "sequence menu", ie: a menu controlled with
+    /// [[MenuDirection::Next]]/[[MenuDirection::Previous]] style requests
+    MenuMove(MenuDirection),
+    /// Cycle to the next [[crate::Focusable]] sharing the currently focused
+    /// element's [[crate::FocusGroup]], skipping all others
+    NextInGroup,
+    /// Cycle to the previous [[crate::Focusable]] sharing the currently
+    /// focused element's [[crate::FocusGroup]], skipping all others
+    PrevInGroup,
+    /// Activate the currently focused [[crate::Focusable]]
+    Action,
+    /// Leave the current [[crate::NavFence]] for its parent one
+    Cancel,
+    /// Move the focus to a specific [[crate::Focusable]]
+    FocusOn(Entity),
+}
+
+/// Events emitted by the navigation system in response to a [[NavRequest]]
+#[derive(Clone, Debug)]
+pub enum NavEvent {
+    /// The focused element changed. [[NavEvent::FocusChanged::from]] lists
+    /// the entities that lose focus (closest first) and
+    /// [[NavEvent::FocusChanged::to]] the ones that gain it (closest first)
+    FocusChanged {
+        to: NonEmpty<Entity>,
+        from: NonEmpty<Entity>,
+    },
+    /// The [[NavRequest]] couldn't be satisfied and was therefore ignored
+    Caught {
+        from: NonEmpty<Entity>,
+        request: NavRequest,
+    },
+}
+impl NavEvent {
+    /// Convenience constructor for when `to` is a single `Entity`
+    pub(crate) fn focus_changed(to: Entity, from: NonEmpty<Entity>) -> NavEvent {
+        NavEvent::FocusChanged {
+            to: NonEmpty::new(to),
+            from,
+        }
+    }
+}
+
+/// Direction for a [[NavRequest::Move]]
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+impl Direction {
+    /// Is `other` reachable from `this` when moving in this direction?
+    pub(crate) fn is_in(&self, this: Vec2, other: Vec2) -> bool {
+        let diff = other - this;
+        match self {
+            Direction::Up => diff.y > diff.x.abs(),
+            Direction::Down => -diff.y > diff.x.abs(),
+            Direction::Right => diff.x > diff.y.abs(),
+            Direction::Left => -diff.x > diff.y.abs(),
+        }
+    }
+}
+
+/// Direction for a [[NavRequest::MenuMove]]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuDirection {
+    Next,
+    Previous,
+}
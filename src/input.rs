@@ -0,0 +1,116 @@
+//! Optional `leafwing_input_manager` integration.
+//!
+//! Enable the `input` feature and add [[NavInputPlugin]] to your app to get
+//! [[NavRequest]]s out of device input, instead of hand-rolling key/gamepad
+//! reading before calling into `listen_nav_requests`.
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+use crate::{Direction, MenuDirection, NavRequest};
+
+/// The actions recognized by the navigation input subsystem.
+///
+/// Bind these to whatever input your game prefers; [[NavInputPlugin]] ships
+/// sane defaults (arrow keys, WASD, left stick, D-pad, south/east buttons).
+#[derive(Actionlike, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NavAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Action,
+    Cancel,
+    Next,
+    Previous,
+}
+impl NavAction {
+    /// Arrow keys + WASD + left stick + D-pad for movement, Enter/South for
+    /// [[NavAction::Action]], Escape/East for [[NavAction::Cancel]], Tab/right
+    /// shoulder button for [[NavAction::Next]], Shift+Tab/left shoulder
+    /// button for [[NavAction::Previous]].
+    fn default_input_map() -> InputMap<NavAction> {
+        use GamepadAxisType::*;
+        use GamepadButtonType::*;
+        use KeyCode::*;
+        const STICK_THRESHOLD: f32 = 0.5;
+        let mut input_map = InputMap::new([
+            (Up, NavAction::Up),
+            (W, NavAction::Up),
+            (Down, NavAction::Down),
+            (S, NavAction::Down),
+            (Left, NavAction::Left),
+            (A, NavAction::Left),
+            (Right, NavAction::Right),
+            (D, NavAction::Right),
+            (Return, NavAction::Action),
+            (Escape, NavAction::Cancel),
+            (Tab, NavAction::Next),
+        ]);
+        input_map
+            .insert_multiple([
+                (DPadUp, NavAction::Up),
+                (DPadDown, NavAction::Down),
+                (DPadLeft, NavAction::Left),
+                (DPadRight, NavAction::Right),
+                (South, NavAction::Action),
+                (East, NavAction::Cancel),
+                (RightTrigger, NavAction::Next),
+                (LeftTrigger, NavAction::Previous),
+            ])
+            .insert_multiple([
+                (
+                    SingleAxis::positive_only(LeftStickY, STICK_THRESHOLD),
+                    NavAction::Up,
+                ),
+                (
+                    SingleAxis::negative_only(LeftStickY, -STICK_THRESHOLD),
+                    NavAction::Down,
+                ),
+                (
+                    SingleAxis::negative_only(LeftStickX, -STICK_THRESHOLD),
+                    NavAction::Left,
+                ),
+                (
+                    SingleAxis::positive_only(LeftStickX, STICK_THRESHOLD),
+                    NavAction::Right,
+                ),
+            ])
+            .insert(UserInput::chord([LShift, Tab]), NavAction::Previous);
+        input_map
+    }
+}
+
+/// Translate `just_pressed` [[NavAction]]s into [[NavRequest]]s.
+fn emit_nav_requests(actions: Res<ActionState<NavAction>>, mut requests: EventWriter<NavRequest>) {
+    use NavAction::*;
+    let translations = [
+        (Up, NavRequest::Move(Direction::Up)),
+        (Down, NavRequest::Move(Direction::Down)),
+        (Left, NavRequest::Move(Direction::Left)),
+        (Right, NavRequest::Move(Direction::Right)),
+        (Action, NavRequest::Action),
+        (Cancel, NavRequest::Cancel),
+        (Next, NavRequest::MenuMove(MenuDirection::Next)),
+        (Previous, NavRequest::MenuMove(MenuDirection::Previous)),
+    ];
+    for (action, request) in translations {
+        if actions.just_pressed(action) {
+            requests.send(request);
+        }
+    }
+}
+
+/// Maps device input to [[NavRequest]]s using `leafwing_input_manager`.
+///
+/// Add this plugin alongside [[crate::NavigationPlugin]] so the whole
+/// navigation stack works out of the box, without a game needing to read
+/// keys/gamepads itself.
+pub struct NavInputPlugin;
+impl Plugin for NavInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(InputManagerPlugin::<NavAction>::default())
+            .insert_resource(NavAction::default_input_map())
+            .insert_resource(ActionState::<NavAction>::default())
+            .add_system(emit_nav_requests);
+    }
+}
@@ -4,9 +4,14 @@
 // All "helper functions" are defined after `listen_nav_requests`,
 // algorithms are specified over `listen_nav_requests`. While structs and enums
 // are defined before all.
+mod accessibility;
 mod events;
+#[cfg(feature = "input")]
+mod input;
+mod validation;
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
 use std::num::NonZeroUsize;
 
@@ -15,7 +20,13 @@ use bevy::math::Vec3Swizzles;
 use bevy::prelude::*;
 use non_empty_vec::NonEmpty;
 
+#[cfg(not(feature = "tts"))]
+pub use crate::accessibility::FocusAnnounce;
+pub use crate::accessibility::{AccessibilityPlugin, FocusLabel};
 pub use crate::events::{Direction, MenuDirection, NavEvent, NavRequest};
+#[cfg(feature = "input")]
+pub use crate::input::{NavAction, NavInputPlugin};
+use crate::validation::validate_nav_graph;
 
 #[derive(SystemParam)]
 struct NavQueries<'w, 's> {
@@ -24,6 +35,8 @@ struct NavQueries<'w, 's> {
     focusables: Query<'w, 's, (Entity, &'static Focusable), With<Focusable>>,
     nav_fences: Query<'w, 's, (Entity, &'static NavFence), With<NavFence>>,
     transform: Query<'w, 's, &'static GlobalTransform>,
+    focus_groups: Query<'w, 's, &'static FocusGroup>,
+    focus_orders: Query<'w, 's, &'static FocusOrder>,
 }
 
 #[derive(Clone, Debug, Copy, PartialEq)]
@@ -180,6 +193,47 @@ impl Focusable {
     }
 }
 
+/// Marks a [[Focusable]] as belonging to a navigation category.
+///
+/// Use with [[NavRequest::NextInGroup]]/[[NavRequest::PrevInGroup]] to cycle
+/// only through the `Focusable`s sharing a group, skipping the rest. Eg: a
+/// settings screen can tab only through sliders, then only through buttons.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FocusGroup(pub u32);
+
+/// An explicit tab order for a [[Focusable]], independent of ECS child
+/// ordering.
+///
+/// When present, the `MenuMove` path sorts siblings by this value before
+/// picking the next/previous one, so authors get a deterministic tab order
+/// even if children are spawned out of order or reparented. `Focusable`s
+/// without a `FocusOrder` sort after those that have one. Use
+/// [[FocusOrderBuilder]] to assign sequential values to a menu's fields.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FocusOrder(pub u32);
+
+/// Assigns sequential [[FocusOrder]] values to a menu's fields.
+///
+/// ```rust,ignore
+/// let mut order = FocusOrderBuilder::new();
+/// let play_button = (spawn_button(), order.next());
+/// let options_button = (spawn_button(), order.next());
+/// ```
+#[derive(Default)]
+pub struct FocusOrderBuilder(u32);
+impl FocusOrderBuilder {
+    pub fn new() -> Self {
+        FocusOrderBuilder(0)
+    }
+
+    /// The next sequential [[FocusOrder]], starting at 0.
+    pub fn next(&mut self) -> FocusOrder {
+        let order = FocusOrder(self.0);
+        self.0 += 1;
+        order
+    }
+}
+
 /// The currently _focused_ [[Focusable]]
 ///
 /// You cannot edit it or create new `Focused` component. To set an arbitrary
@@ -239,6 +293,27 @@ fn resolve_sequence(
     }
 }
 
+/// Like [[resolve_sequence]], but wraps around the ends of `siblings`
+/// instead of being caught at the boundary, so
+/// [[NavRequest::NextInGroup]]/[[NavRequest::PrevInGroup]] actually cycle
+/// through the group.
+fn resolve_group_sequence(
+    focused: Entity,
+    direction: MenuDirection,
+    siblings: &[Entity],
+) -> Option<Entity> {
+    if siblings.len() < 2 {
+        return None;
+    }
+    let focused_index = siblings.iter().position(|e| *e == focused)?;
+    let len = siblings.len();
+    let next_index = match direction {
+        MenuDirection::Next => (focused_index + 1) % len,
+        MenuDirection::Previous => (focused_index + len - 1) % len,
+    };
+    Some(siblings[next_index])
+}
+
 /// Resolve `request` where the focused element is `focused`
 fn resolve(
     focused: Entity,
@@ -252,11 +327,6 @@ fn resolve(
         queries.focusables.get(focused).is_ok(),
         "The resolution algorithm MUST go from a focusable element"
     );
-    assert!(
-        !from.contains(&focused),
-        "Navigation graph cycle detected! This panic has prevented a stack overflow, \
-        please check usages of `NavFence::reachable_from`"
-    );
 
     let mut from = (from, focused).into();
 
@@ -271,6 +341,25 @@ fn resolve(
                 None => NavEvent::Caught { from, request },
             }
         }
+        group_move @ (NextInGroup | PrevInGroup) => {
+            let siblings = match parent_nav_fence(focused, queries) {
+                Some((parent, _)) => ordered_children_focusables(parent, queries),
+                None => queries.focusables.iter().map(|tpl| tpl.0).collect(),
+            };
+            let group = queries.focus_groups.get(focused).ok().copied();
+            let grouped: Vec<Entity> = siblings
+                .into_iter()
+                .filter(|e| queries.focus_groups.get(*e).ok().copied() == group)
+                .collect();
+            let direction = match group_move {
+                NextInGroup => MenuDirection::Next,
+                _ => MenuDirection::Previous,
+            };
+            match resolve_group_sequence(focused, direction, &grouped) {
+                Some(to) => NavEvent::focus_changed(to, from),
+                None => NavEvent::Caught { from, request },
+            }
+        }
         Cancel => match parent_nav_fence(focused, queries) {
             Some((_, to)) if to.focus_parent.is_some() => {
                 let to = to.focus_parent.unwrap();
@@ -299,7 +388,7 @@ fn resolve(
                 Some(inner) => inner,
                 None => return NavEvent::Caught { from, request },
             };
-            let siblings = children_focusables(parent, queries);
+            let siblings = ordered_children_focusables(parent, queries);
             if nav_fence.is_sequence_menu {
                 match resolve_sequence(focused, menu_direction, &siblings) {
                     Some(to) => NavEvent::focus_changed(*to, from),
@@ -307,6 +396,15 @@ fn resolve(
                 }
             } else {
                 let focused = nav_fence.focus_parent.unwrap();
+                // `validate_nav_graph` catches cycles at startup, but keep this
+                // backstop so a cyclic graph slipping through (eg: mutated at
+                // runtime) fails fast instead of recursing forever.
+                assert!(
+                    !from.contains(&focused),
+                    "Navigation graph cycle detected while resolving MenuMove \
+                    through {focused:?}; validate_nav_graph should have caught \
+                    this at startup"
+                );
                 resolve(focused, request, queries, from.into())
             }
         }
@@ -328,39 +426,49 @@ fn listen_nav_requests(
     mut events: EventWriter<NavEvent>,
     mut commands: Commands,
 ) {
-    // TODO: this most likely breaks when there is more than a single event
     // When no `Focused` found, should take a direct child of a
     // `NavFence.focus_parent == None`
+    let mut current_focused = focused.get_single().unwrap_or_else(|err| {
+        assert!(
+            !matches!(err, QuerySingleError::MultipleEntities(_)),
+            "Multiple entities with Focused component, this should not happen"
+        );
+        queries.focusables.iter().next().unwrap().0
+    });
+
+    // The net state each entity should end up in this frame, keyed by
+    // entity so that chained requests only write their final state via
+    // `Commands`, rather than every intermediate state they passed through.
+    let mut pending_states: HashMap<Entity, FocusState> = HashMap::new();
+
     for request in requests.iter() {
-        // TODO: This code needs cleanup
-        let focused_id = focused.get_single().unwrap_or_else(|err| {
-            assert!(
-                !matches!(err, QuerySingleError::MultipleEntities(_)),
-                "Multiple entities with Focused component, this should not happen"
-            );
-            queries.focusables.iter().next().unwrap().0
-        });
-        let event = resolve(focused_id, *request, &queries, Vec::new());
+        let event = resolve(current_focused, *request, &queries, Vec::new());
         if let NavEvent::FocusChanged { to, from } = &event {
-            let focused = Focusable::with_state(FocusState::Focused);
-            let inert = Focusable::with_state(FocusState::Inert);
-            let dormant = Focusable::with_state(FocusState::Dormant);
-            let active = Focusable::with_state(FocusState::Active);
-
             let (disable, put_to_sleep) = from.split_last();
-            commands.entity(*disable).insert(inert).remove::<Focused>();
+            pending_states.insert(*disable, FocusState::Inert);
             for entity in put_to_sleep {
-                commands.entity(*entity).insert(dormant).remove::<Focused>();
+                pending_states.insert(*entity, FocusState::Dormant);
             }
 
             let (focus, activate) = to.split_first();
-            commands.entity(*focus).insert(focused).insert(Focused);
+            pending_states.insert(*focus, FocusState::Focused);
             for entity in activate {
-                commands.entity(*entity).insert(active);
+                pending_states.insert(*entity, FocusState::Active);
             }
-        };
+            current_focused = *focus;
+        }
         events.send(event);
     }
+
+    for (entity, focus_state) in pending_states {
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert(Focusable::with_state(focus_state));
+        if focus_state == FocusState::Focused {
+            entity_commands.insert(Focused);
+        } else {
+            entity_commands.remove::<Focused>();
+        }
+    }
 }
 
 /// The [[NavFence]] containing `focusable`, if any
@@ -391,6 +499,20 @@ fn children_focusables(nav_fence: Entity, queries: &NavQueries) -> Vec<Entity> {
     }
 }
 
+/// Like [[children_focusables]], but sorted by [[FocusOrder]] so the
+/// `MenuMove` path gets a deterministic tab order independent of spawn
+/// order. Siblings without a `FocusOrder` sort after those that have one.
+fn ordered_children_focusables(nav_fence: Entity, queries: &NavQueries) -> Vec<Entity> {
+    let mut children = children_focusables(nav_fence, queries);
+    children.sort_by_key(|e| {
+        queries
+            .focus_orders
+            .get(*e)
+            .map_or(u32::MAX, |order| order.0)
+    });
+    children
+}
+
 /// Which `Entity` in `siblings` is not _inert_, or the first in `siblings` if
 /// none found.
 fn non_inert_within<'a, 'b>(siblings: &'a [Entity], queries: &'b NavQueries) -> Option<&'a Entity> {
@@ -441,12 +563,13 @@ fn root_path(mut from: Entity, queries: &NavQueries) -> NonEmpty<Entity> {
             Some((_, fence)) if fence.focus_parent.is_some() => fence.focus_parent.unwrap(),
             _ => return ret,
         };
-        if ret.contains(&from) {
-            panic!(
-                "Navigation graph cycle detected! This panic has prevented a stack \
-                overflow, please check usages of `NavFence::reachable_from`"
-            );
-        }
+        // `validate_nav_graph` catches cycles at startup, but keep this
+        // backstop so a cyclic graph slipping through doesn't spin forever.
+        assert!(
+            !ret.contains(&from),
+            "Navigation graph cycle detected while walking root_path through \
+            {from:?}; validate_nav_graph should have caught this at startup"
+        );
         ret.push(from);
     }
 }
@@ -456,13 +579,18 @@ impl Plugin for NavigationPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<NavRequest>()
             .add_event::<NavEvent>()
+            .add_startup_system_to_stage(StartupStage::PostStartup, validate_nav_graph)
+            .add_system(validate_nav_graph)
             .add_system(listen_nav_requests);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::trim_common_tail;
+    use super::{resolve_group_sequence, trim_common_tail};
+    use crate::MenuDirection;
+    use bevy::prelude::Entity;
+
     #[test]
     fn test_trim_common_tail() {
         use non_empty_vec::ne_vec;
@@ -472,4 +600,33 @@ mod tests {
         assert_eq!(v1, ne_vec![1, 2, 3, 4]);
         assert_eq!(v2, ne_vec![3, 2, 1, 4]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn resolve_group_sequence_wraps_around() {
+        let siblings: Vec<Entity> = (0..3).map(Entity::from_raw).collect();
+        let last = siblings[2];
+        let first = siblings[0];
+        assert_eq!(
+            resolve_group_sequence(last, MenuDirection::Next, &siblings),
+            Some(first)
+        );
+        assert_eq!(
+            resolve_group_sequence(first, MenuDirection::Previous, &siblings),
+            Some(last)
+        );
+    }
+
+    #[test]
+    fn resolve_group_sequence_single_member_is_none() {
+        let focused = Entity::from_raw(0);
+        let siblings = [focused];
+        assert_eq!(
+            resolve_group_sequence(focused, MenuDirection::Next, &siblings),
+            None
+        );
+        assert_eq!(
+            resolve_group_sequence(focused, MenuDirection::Previous, &siblings),
+            None
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Startup validation of the navigation graph.
+//!
+//! Walks the whole [[crate::NavFence]]/[[crate::Focusable]] graph up front
+//! instead of panicking mid-resolution the first time a user navigates into
+//! a cycle (see the `assert!` in `resolve`), so both documented invariants
+//! are checked before anything goes wrong at runtime:
+//!
+//! 1. There must be no cycles in the navigation graph.
+//! 2. Every `NavFence` must have at least one reachable child `Focusable`.
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::{children_focusables, NavFence, NavQueries};
+
+/// One node's remaining, not-yet-visited neighbours in the graph being
+/// walked by [[detect_cycle]].
+type Frame = (Entity, Vec<Entity>);
+
+/// The edges out of `entity`, one-directional:
+/// * a `Focusable` points *down* into every `NavFence` reachable from it (ie:
+///   every `NavFence` whose `focus_parent` is `entity`)
+/// * a `NavFence` points *down* into the `Focusable`s it structurally
+///   contains (its [[children_focusables]])
+///
+/// A cycle therefore only exists when activating a `Focusable` leads, via
+/// some chain of `NavFence::reachable_from` and containment, back to
+/// itself — not merely because a `NavFence` and its own `focus_parent` are
+/// both present in the graph.
+fn neighbors(
+    entity: Entity,
+    fences: &Query<(Entity, &NavFence)>,
+    queries: &NavQueries,
+) -> Vec<Entity> {
+    let mut neighbors: Vec<Entity> = fences
+        .iter()
+        .filter(|(_, fence)| fence.focus_parent == Some(entity))
+        .map(|(fence_entity, _)| fence_entity)
+        .collect();
+    if fences.get(entity).is_ok() {
+        neighbors.extend(children_focusables(entity, queries));
+    }
+    neighbors
+}
+
+/// Iterative DFS from `start`, tracking a `visited` set plus an `on_stack`
+/// set: a back-edge to a node currently `on_stack` closes a cycle. Returns
+/// the exact entity chain forming the loop, with the entity that closes it
+/// first and last.
+fn detect_cycle(
+    start: Entity,
+    fences: &Query<(Entity, &NavFence)>,
+    queries: &NavQueries,
+    visited: &mut HashSet<Entity>,
+) -> Option<Vec<Entity>> {
+    let mut on_stack = HashSet::new();
+    let mut path = vec![start];
+    let mut stack: Vec<Frame> = vec![(start, neighbors(start, fences, queries))];
+    visited.insert(start);
+    on_stack.insert(start);
+
+    while let Some((node, remaining)) = stack.last_mut() {
+        match remaining.pop() {
+            Some(next) if on_stack.contains(&next) => {
+                // Drop the part of the DFS path leading into the loop.
+                let loop_start = path.iter().position(|e| *e == next)?;
+                let mut cycle = path.split_off(loop_start);
+                cycle.push(next);
+                return Some(cycle);
+            }
+            Some(next) if visited.insert(next) => {
+                on_stack.insert(next);
+                path.push(next);
+                let next_neighbors = neighbors(next, fences, queries);
+                stack.push((next, next_neighbors));
+            }
+            Some(_already_visited_elsewhere) => {}
+            None => {
+                on_stack.remove(node);
+                path.pop();
+                stack.pop();
+            }
+        }
+    }
+    None
+}
+
+/// Check the navigation graph for cycles and for `NavFence`s with no
+/// reachable child `Focusable`, logging the exact entity chain when an
+/// invariant is violated.
+///
+/// Only runs when the graph's *structure* changed since the last run (a
+/// [[NavFence]] was added/changed/removed, or a [[crate::Focusable]] was
+/// added), not on every [[crate::Focusable]] mutation — `listen_nav_requests`
+/// rewrites the focused `Focusable` on every navigation input, and that
+/// alone shouldn't trigger a full graph re-walk.
+pub(crate) fn validate_nav_graph(
+    fences: Query<(Entity, &NavFence)>,
+    queries: NavQueries,
+    changed_fences: Query<(), Changed<NavFence>>,
+    added_focusables: Query<(), Added<crate::Focusable>>,
+    removed_fences: RemovedComponents<NavFence>,
+) {
+    if changed_fences.is_empty()
+        && added_focusables.is_empty()
+        && removed_fences.iter().next().is_none()
+    {
+        return;
+    }
+
+    let mut visited = HashSet::new();
+    for (fence_entity, _) in fences.iter() {
+        if !visited.contains(&fence_entity) {
+            if let Some(cycle) = detect_cycle(fence_entity, &fences, &queries, &mut visited) {
+                error!(
+                    "Navigation graph cycle detected! The following chain of NavFence/Focusable \
+                    entities forms a loop through NavFence::reachable_from: {cycle:?}"
+                );
+            }
+        }
+    }
+    for (fence_entity, _) in fences.iter() {
+        if children_focusables(fence_entity, &queries).is_empty() {
+            error!(
+                "NavFence {fence_entity:?} has no reachable child Focusable; every NavFence must \
+                have at least one child Focusable in the ui graph when sending a NavRequest"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use bevy::ecs::system::SystemState;
+    use bevy::hierarchy::BuildWorldChildren;
+    use bevy::prelude::*;
+
+    use super::detect_cycle;
+    use crate::{Focusable, NavFence, NavQueries};
+
+    fn state(world: &mut World) -> SystemState<(Query<(Entity, &NavFence)>, NavQueries)> {
+        SystemState::new(world)
+    }
+
+    #[test]
+    fn detect_cycle_finds_known_cycle() {
+        let mut world = World::new();
+        let focusable_a = world.spawn(Focusable::default()).id();
+        let focusable_b = world.spawn(Focusable::default()).id();
+        // fence_a is reachable from focusable_b and contains focusable_a;
+        // fence_b is reachable from focusable_a and contains focusable_b:
+        // fence_a -> focusable_a -> fence_b -> focusable_b -> fence_a
+        let fence_a = world.spawn(NavFence::reachable_from(focusable_b)).id();
+        let fence_b = world.spawn(NavFence::reachable_from(focusable_a)).id();
+        world.entity_mut(fence_a).push_children(&[focusable_a]);
+        world.entity_mut(fence_b).push_children(&[focusable_b]);
+
+        let mut system_state = state(&mut world);
+        let (fences, queries) = system_state.get(&world);
+        let mut visited = HashSet::new();
+        let cycle = detect_cycle(fence_a, &fences, &queries, &mut visited);
+        assert!(cycle.is_some());
+    }
+
+    #[test]
+    fn detect_cycle_none_when_acyclic() {
+        let mut world = World::new();
+        let focusable_a = world.spawn(Focusable::default()).id();
+        let fence_root = world.spawn(NavFence::root()).id();
+        world.entity_mut(fence_root).push_children(&[focusable_a]);
+
+        let mut system_state = state(&mut world);
+        let (fences, queries) = system_state.get(&world);
+        let mut visited = HashSet::new();
+        assert!(detect_cycle(fence_root, &fences, &queries, &mut visited).is_none());
+    }
+}